@@ -1,17 +1,264 @@
 use anyhow::Context;
-use crossterm::event::{Event, EventStream, KeyCode};
+use crossterm::event::{Event, EventStream, KeyCode, KeyModifiers};
 use ratatui::{
     layout::{Layout, Margin},
-    style::Style,
-    text::{Line, Text},
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
     widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarState},
 };
-use std::{ffi::OsStr, io::stderr, process::Stdio};
+use std::{
+    ffi::{OsStr, OsString},
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    process::Stdio,
+};
 use tokio::io::{AsyncRead, AsyncReadExt};
 use tokio_stream::StreamExt;
 
+/// How many lines of scrollback each emulator retains.
+const SCROLLBACK_LEN: usize = 10_000;
+
+/// Width each emulator is sized to while line-wrapping is disabled, so logical
+/// lines stay intact and are reached via horizontal scrolling instead.
+const UNWRAPPED_COLS: u16 = 1000;
+
 pub fn print_usage(arg0: &OsStr) {
-    eprintln!("Usage: {} <command> [args]", arg0.to_string_lossy());
+    let name = arg0.to_string_lossy();
+    eprintln!("Usage: {name} [--pty] [--save-stdout PATH] [--save-stderr PATH] [--save PATH] <command> [args]");
+    eprintln!("       {name} --replay PATH");
+}
+
+/// Map a vt100 color onto the equivalent ratatui color. `Default` becomes
+/// `Reset` so the terminal's own default fg/bg shows through.
+fn vt_color(color: vt100::Color) -> Color {
+    match color {
+        vt100::Color::Default => Color::Reset,
+        vt100::Color::Idx(i) => Color::Indexed(i),
+        vt100::Color::Rgb(r, g, b) => Color::Rgb(r, g, b),
+    }
+}
+
+/// Build the ratatui style (colors + SGR attributes) for a single grid cell.
+fn cell_style(cell: &vt100::Cell) -> Style {
+    let mut style = Style::default()
+        .fg(vt_color(cell.fgcolor()))
+        .bg(vt_color(cell.bgcolor()));
+    if cell.bold() {
+        style = style.add_modifier(Modifier::BOLD);
+    }
+    if cell.italic() {
+        style = style.add_modifier(Modifier::ITALIC);
+    }
+    if cell.underline() {
+        style = style.add_modifier(Modifier::UNDERLINED);
+    }
+    if cell.inverse() {
+        style = style.add_modifier(Modifier::REVERSED);
+    }
+    style
+}
+
+/// Consume the value that follows a flag, erroring if it's missing.
+fn take_value(args: &mut Vec<OsString>, flag: &str) -> anyhow::Result<PathBuf> {
+    if args.is_empty() {
+        anyhow::bail!("{flag} requires a path argument");
+    }
+    Ok(PathBuf::from(args.remove(0)))
+}
+
+/// Append one event to a combined transcript. The record is a text header
+/// (`<millis> <source> <len>`) followed by the raw bytes, so replay can
+/// reconstruct both the interleaving and the timestamps.
+fn write_transcript_event<W: Write>(w: &mut W, event: &OutputEvent) -> std::io::Result<()> {
+    writeln!(
+        w,
+        "{} {} {}",
+        event.when.as_millis(),
+        event.source.marker(),
+        event.bytes.len()
+    )?;
+    w.write_all(&event.bytes)?;
+    w.write_all(b"\n")
+}
+
+/// Read a transcript written by [`write_transcript_event`] back into events.
+fn read_transcript(path: &Path) -> anyhow::Result<Vec<OutputEvent>> {
+    let data = std::fs::read(path)
+        .with_context(|| format!("Failed to read transcript {}", path.display()))?;
+    parse_transcript(&data)
+}
+
+/// Parse the raw bytes of a transcript into events (factored out of
+/// [`read_transcript`] so it can be exercised without touching the filesystem).
+fn parse_transcript(data: &[u8]) -> anyhow::Result<Vec<OutputEvent>> {
+    let mut events = Vec::new();
+    let mut i = 0;
+    while i < data.len() {
+        let nl = data[i..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .context("truncated transcript header")?
+            + i;
+        let header = std::str::from_utf8(&data[i..nl]).context("invalid transcript header")?;
+        let mut parts = header.split(' ');
+        let millis: u64 = parts
+            .next()
+            .context("missing timestamp")?
+            .parse()
+            .context("invalid timestamp")?;
+        let source = match parts.next() {
+            Some(m) if m == Source::Stdout.marker() => Source::Stdout,
+            Some(m) if m == Source::Stderr.marker() => Source::Stderr,
+            _ => anyhow::bail!("invalid source marker in transcript"),
+        };
+        let len: usize = parts
+            .next()
+            .context("missing length")?
+            .parse()
+            .context("invalid length")?;
+        let body_start = nl + 1;
+        let body_end = body_start + len;
+        anyhow::ensure!(body_end <= data.len(), "truncated transcript body");
+        events.push(OutputEvent {
+            when: std::time::Duration::from_millis(millis),
+            source,
+            bytes: data[body_start..body_end].to_vec(),
+        });
+        i = body_end + 1; // skip the record's trailing newline
+    }
+    Ok(events)
+}
+
+/// Strip ANSI escape sequences (CSI/OSC and simple escapes) from a decoded
+/// chunk so the merged transcript stays readable without a full emulator.
+fn strip_ansi(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            // CSI: consume until the final byte in the 0x40..=0x7e range
+            Some('[') => {
+                for f in chars.by_ref() {
+                    if ('\u{40}'..='\u{7e}').contains(&f) {
+                        break;
+                    }
+                }
+            }
+            // OSC: consume until BEL or ST (ESC \)
+            Some(']') => {
+                while let Some(f) = chars.next() {
+                    if f == '\u{7}' {
+                        break;
+                    }
+                    if f == '\u{1b}' {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            // any other two-byte escape is simply dropped
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Render a single event into lines for the merged view, prefixing each with a
+/// gutter holding the arrival time and a source marker and coloring it by
+/// source. Rendering per-event lets callers cache and only process new arrivals.
+fn event_to_lines(event: &OutputEvent) -> Vec<Line<'static>> {
+    let text = strip_ansi(&String::from_utf8_lossy(&event.bytes));
+    let gutter = format!("[+{:>8.3}s {}] ", event.when.as_secs_f64(), event.source.marker());
+    let blank = " ".repeat(gutter.len());
+    let style = Style::default().fg(event.source.color());
+    // a chunk may hold several newline-separated lines; only the first carries
+    // the timestamp, continuations get a blank gutter. A trailing empty segment
+    // from a final '\n' is dropped.
+    let mut parts: Vec<&str> = text.split('\n').collect();
+    if matches!(parts.last(), Some(&"")) {
+        parts.pop();
+    }
+    let mut lines = Vec::with_capacity(parts.len());
+    for (i, line) in parts.into_iter().enumerate() {
+        let prefix = if i == 0 { gutter.clone() } else { blank.clone() };
+        lines.push(Line::from(vec![
+            Span::raw(prefix),
+            Span::styled(line.to_string(), style),
+        ]));
+    }
+    lines
+}
+
+/// Walk an emulator screen grid and turn each row into a ratatui `Line`,
+/// coalescing runs of cells that share a style into a single `Span`.
+fn screen_to_text(screen: &vt100::Screen) -> Text<'static> {
+    let (rows, cols) = screen.size();
+    let mut lines = Vec::with_capacity(rows as usize);
+    for row in 0..rows {
+        let mut spans: Vec<Span> = Vec::new();
+        let mut run = String::new();
+        let mut run_style = Style::default();
+        for col in 0..cols {
+            let (contents, style) = match screen.cell(row, col) {
+                Some(cell) => {
+                    let c = cell.contents();
+                    (if c.is_empty() { " ".to_string() } else { c }, cell_style(cell))
+                }
+                None => (" ".to_string(), Style::default()),
+            };
+            if !run.is_empty() && style != run_style {
+                spans.push(Span::styled(std::mem::take(&mut run), run_style));
+            }
+            run_style = style;
+            run.push_str(&contents);
+        }
+        if !run.is_empty() {
+            spans.push(Span::styled(run, run_style));
+        }
+        lines.push(Line::from(spans));
+    }
+    Text::from(lines)
+}
+
+/// Interior `(rows, cols)` of the stdout split pane for a given terminal size.
+/// The pty is sized to this (not the whole terminal) so the child wraps and
+/// colorizes to the width actually visible in the pane.
+fn stdout_pane_size(cols: u16, rows: u16) -> pty_process::Size {
+    pty_process::Size::new(rows.saturating_sub(2), (cols / 2).saturating_sub(2))
+}
+
+/// Keeps the spawned child alive regardless of which spawning path we took.
+enum ChildHandle {
+    Plain(tokio::process::Child),
+    Pty(pty_process::Child),
+}
+
+/// Horizontal scroll position for a pane when line-wrapping is disabled,
+/// modelled as a position clamped to a maximum (à la gobang).
+#[derive(Debug, Default, Clone, Copy)]
+struct HorizontalScroll {
+    pos: u16,
+    max: u16,
+}
+
+impl HorizontalScroll {
+    fn scroll_left(&mut self, by: u16) {
+        self.pos = self.pos.saturating_sub(by);
+    }
+
+    fn scroll_right(&mut self, by: u16) {
+        self.pos = self.pos.saturating_add(by).min(self.max);
+    }
+
+    fn set_max(&mut self, max: u16) {
+        self.max = max;
+        self.pos = self.pos.min(max);
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -20,6 +267,48 @@ enum ActiveWidget {
     Stderr,
 }
 
+/// Which stream a chunk of output arrived on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Source {
+    Stdout,
+    Stderr,
+}
+
+impl Source {
+    /// Short marker shown in the merged view's gutter.
+    fn marker(self) -> &'static str {
+        match self {
+            Source::Stdout => "O",
+            Source::Stderr => "E",
+        }
+    }
+
+    /// Color each source's text is tagged with in the merged view.
+    fn color(self) -> Color {
+        match self {
+            Source::Stdout => Color::Reset,
+            Source::Stderr => Color::Red,
+        }
+    }
+}
+
+/// A single read from the child, tagged with the time it arrived and which
+/// stream it came from. Recording raw bytes keeps the original interleaving so
+/// the merged view (and a later replay) can reconstruct the true arrival order.
+struct OutputEvent {
+    when: std::time::Duration,
+    source: Source,
+    bytes: Vec<u8>,
+}
+
+/// How the output is laid out: the two side-by-side panes or a single merged,
+/// chronologically interleaved pane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ViewMode {
+    Split,
+    Merged,
+}
+
 impl ActiveWidget {
     fn switch(&mut self) {
         match self {
@@ -39,27 +328,157 @@ async fn main() -> anyhow::Result<()> {
         return Ok(());
     }
 
-    let cmd = args.remove(0);
+    // iosplit's own flags come before the child command.
+    let mut use_pty = false;
+    let mut save_stdout: Option<PathBuf> = None;
+    let mut save_stderr: Option<PathBuf> = None;
+    let mut save_transcript: Option<PathBuf> = None;
+    let mut replay: Option<PathBuf> = None;
+    while let Some(arg) = args.first().and_then(|a| a.to_str()) {
+        match arg {
+            "--pty" => {
+                use_pty = true;
+                args.remove(0);
+            }
+            "--save-stdout" => {
+                args.remove(0);
+                save_stdout = Some(take_value(&mut args, "--save-stdout")?);
+            }
+            "--save-stderr" => {
+                args.remove(0);
+                save_stderr = Some(take_value(&mut args, "--save-stderr")?);
+            }
+            "--save" => {
+                args.remove(0);
+                save_transcript = Some(take_value(&mut args, "--save")?);
+            }
+            "--replay" => {
+                args.remove(0);
+                replay = Some(take_value(&mut args, "--replay")?);
+            }
+            _ => break,
+        }
+    }
+
+    // In replay mode there's no child command to run.
+    if replay.is_none() && args.is_empty() {
+        print_usage(&arg0);
+        return Ok(());
+    }
+
+    // Load a saved transcript up front so errors surface before the UI starts.
+    let replay_events = match &replay {
+        Some(path) => read_transcript(path)?,
+        None => Vec::new(),
+    };
+
+    // Most programs switch to block buffering and drop color when their stdout
+    // isn't a tty. In `--pty` mode we hand the child a pseudo-terminal so it
+    // stays unbuffered and colorized; stderr still gets its own pipe so the two
+    // panes stay separate. Without it we keep the plain two-pipe path.
+    let (cols, rows) = crossterm::terminal::size().unwrap_or((80, 24));
 
-    let mut child = tokio::process::Command::new(cmd)
-        .args(args)
-        .stdin(Stdio::null())
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .context("Failed to start process")?;
+    let mut child_stdout: Box<dyn AsyncRead + Unpin>;
+    let mut child_stderr: Box<dyn AsyncRead + Unpin>;
+    let mut pty: Option<pty_process::OwnedWritePty> = None;
+    // keep the child handle alive for as long as the UI is running
+    let _child: Option<ChildHandle>;
 
-    let mut child_stdout = child.stdout.take().context("Failed to get stdout")?;
-    let mut child_stderr = child.stderr.take().context("Failed to get stderr")?;
+    if replay.is_some() {
+        // no child: the readers stay empty and the event log is preloaded below
+        child_stdout = Box::new(tokio::io::empty());
+        child_stderr = Box::new(tokio::io::empty());
+        _child = None;
+    } else {
+        let cmd = args.remove(0);
+        if use_pty {
+            let mut pty_master = pty_process::Pty::new().context("Failed to open pty")?;
+            pty_master
+                .resize(stdout_pane_size(cols, rows))
+                .context("Failed to set pty size")?;
+            let pts = pty_master.pts().context("Failed to get pty slave")?;
+            let mut child = pty_process::Command::new(&cmd)
+                .args(&args)
+                .stderr(Stdio::piped())
+                .spawn(&pts)
+                .context("Failed to start process")?;
+            child_stderr = Box::new(child.stderr.take().context("Failed to get stderr")?);
+            let (pty_out, pty_in) = pty_master.into_split();
+            child_stdout = Box::new(pty_out);
+            pty = Some(pty_in);
+            _child = Some(ChildHandle::Pty(child));
+        } else {
+            let mut child = tokio::process::Command::new(&cmd)
+                .args(&args)
+                .stdin(Stdio::null())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .spawn()
+                .context("Failed to start process")?;
+            child_stdout = Box::new(child.stdout.take().context("Failed to get stdout")?);
+            child_stderr = Box::new(child.stderr.take().context("Failed to get stderr")?);
+            _child = Some(ChildHandle::Plain(child));
+        }
+    }
+
+    // Open any tee targets; the child's bytes are written to these as they
+    // arrive so the capture survives after the TUI exits.
+    let mut save_stdout_file = match &save_stdout {
+        Some(path) => Some(BufWriter::new(
+            File::create(path).with_context(|| format!("Failed to create {}", path.display()))?,
+        )),
+        None => None,
+    };
+    let mut save_stderr_file = match &save_stderr {
+        Some(path) => Some(BufWriter::new(
+            File::create(path).with_context(|| format!("Failed to create {}", path.display()))?,
+        )),
+        None => None,
+    };
+    let mut save_transcript_file = match &save_transcript {
+        Some(path) => Some(BufWriter::new(
+            File::create(path).with_context(|| format!("Failed to create {}", path.display()))?,
+        )),
+        None => None,
+    };
 
     let mut terminal = ratatui::init();
 
-    let mut stdout_buf: Vec<String> = Vec::new();
-    let mut stderr_buf: Vec<String> = Vec::new();
+    // A terminal emulator per stream: `process()` interprets ANSI escapes and
+    // maintains an in-memory screen grid plus scrollback for us. The size is
+    // fixed up to the real pane dimensions on the first draw.
+    let mut stdout_parser = vt100::Parser::new(rows, cols, SCROLLBACK_LEN);
+    let mut stderr_parser = vt100::Parser::new(rows, cols, SCROLLBACK_LEN);
+
+    // the chronological event log feeding the merged view; each read appends an
+    // entry tagged with the time since startup and its source stream
+    let start = std::time::Instant::now();
+    let mut merged_log: Vec<OutputEvent> = Vec::new();
+    let mut view_mode = ViewMode::Split;
+    let mut merged_scroll_offset = 0usize;
+    let mut merged_autoscroll = true;
+    // The split view's `stdout_buf.concat()` + `textwrap::wrap` re-wrap was
+    // already removed when it moved to the incremental vt100 grid (screen_to_text
+    // is O(visible screen)), so the only remaining O(total-output) per-frame path
+    // is the merged view's line building. We cache its rendered lines and only
+    // turn events past `merged_rendered` into lines each frame, making per-frame
+    // cost O(new events).
+    let mut merged_lines: Vec<Line<'static>> = Vec::new();
+    let mut merged_rendered = 0usize;
+
+    // Replay a saved transcript back through the same emulators and event log.
+    for event in replay_events {
+        match event.source {
+            Source::Stdout => stdout_parser.process(&event.bytes),
+            Source::Stderr => stderr_parser.process(&event.bytes),
+        }
+        merged_log.push(event);
+    }
 
     // let mut child_running = true;
-    let mut read_stdout = true;
-    let mut read_stderr = true;
+    // there's nothing to read from in replay mode
+    let mut read_stdout = replay.is_none();
+    let mut read_stderr = replay.is_none();
     // let child_stdout = Some(child_stdout);
     // let child_stderr = Some(child_stderr);
 
@@ -71,8 +490,20 @@ async fn main() -> anyhow::Result<()> {
     let mut stdout_autoscroll = true;
     let mut stderr_autoscroll = true;
 
+    // line-wrapping is on by default; turning it off per pane exposes the
+    // horizontal scroll position used to pan across wide output
+    let mut stdout_wrap = true;
+    let mut stderr_wrap = true;
+    let mut stdout_hscroll = HorizontalScroll::default();
+    let mut stderr_hscroll = HorizontalScroll::default();
+
     let mut active_widget = ActiveWidget::Stdout;
 
+    // a steady tick keeps the select resolving even when there's no child
+    // output or input, so the first frame (and replay's preloaded transcript)
+    // is drawn immediately rather than waiting on a keypress
+    let mut tick = tokio::time::interval(std::time::Duration::from_millis(100));
+
     loop {
         let scroll_page = if let Ok(size) = terminal.size() {
             (size.height as f32 / 3.0).floor() as usize
@@ -87,8 +518,19 @@ async fn main() -> anyhow::Result<()> {
                         read_stdout = false;
                     }
                     Ok(bytes) => {
-                        let data = String::from_utf8_lossy(&out_buf[..bytes]).into_owned();
-                        stdout_buf.push(data);
+                        stdout_parser.process(&out_buf[..bytes]);
+                        if let Some(f) = save_stdout_file.as_mut() {
+                            let _ = f.write_all(&out_buf[..bytes]);
+                        }
+                        let event = OutputEvent {
+                            when: start.elapsed(),
+                            source: Source::Stdout,
+                            bytes: out_buf[..bytes].to_vec(),
+                        };
+                        if let Some(f) = save_transcript_file.as_mut() {
+                            let _ = write_transcript_event(f, &event);
+                        }
+                        merged_log.push(event);
                     }
 
                     _ => {
@@ -102,8 +544,19 @@ async fn main() -> anyhow::Result<()> {
                         read_stderr = false;
                     }
                     Ok(bytes) => {
-                        let data = String::from_utf8_lossy(&err_buf[..bytes]).into_owned();
-                        stderr_buf.push(data);
+                        stderr_parser.process(&err_buf[..bytes]);
+                        if let Some(f) = save_stderr_file.as_mut() {
+                            let _ = f.write_all(&err_buf[..bytes]);
+                        }
+                        let event = OutputEvent {
+                            when: start.elapsed(),
+                            source: Source::Stderr,
+                            bytes: err_buf[..bytes].to_vec(),
+                        };
+                        if let Some(f) = save_transcript_file.as_mut() {
+                            let _ = write_transcript_event(f, &event);
+                        }
+                        merged_log.push(event);
                     }
                     _ => {
                         read_stderr = false;
@@ -113,7 +566,16 @@ async fn main() -> anyhow::Result<()> {
             x = events.next() => {
                 match x {
                     Some(Ok(event)) => {
-                        if let Event::Key(key) = event {
+                        match event {
+                            Event::Resize(cols, rows) => {
+                                // keep the pty slave's window size in sync with the
+                                // stdout pane so the child re-queries its dimensions
+                                if let Some(pty) = pty.as_ref() {
+                                    let _ = pty.resize(stdout_pane_size(cols, rows));
+                                }
+                            }
+                            Event::Key(key) => {
+                            let shift = key.modifiers.contains(KeyModifiers::SHIFT);
                             match key.code {
                                 KeyCode::Esc => {
                                     break;
@@ -121,48 +583,120 @@ async fn main() -> anyhow::Result<()> {
                                 KeyCode::Tab => {
                                     active_widget.switch();
                                 }
+                                KeyCode::Char('m') => {
+                                    // toggle between the split and merged views
+                                    view_mode = match view_mode {
+                                        ViewMode::Split => ViewMode::Merged,
+                                        ViewMode::Merged => ViewMode::Split,
+                                    };
+                                }
+                                KeyCode::Char('w') => {
+                                    // toggle wrapping for the active pane and reset
+                                    // its horizontal position back to the left edge
+                                    if active_widget == ActiveWidget::Stdout {
+                                        stdout_wrap = !stdout_wrap;
+                                        stdout_hscroll.pos = 0;
+                                    } else {
+                                        stderr_wrap = !stderr_wrap;
+                                        stderr_hscroll.pos = 0;
+                                    }
+                                }
+                                KeyCode::Left => {
+                                    if active_widget == ActiveWidget::Stdout {
+                                        stdout_hscroll.scroll_left(1);
+                                    } else {
+                                        stderr_hscroll.scroll_left(1);
+                                    }
+                                }
+                                KeyCode::Right => {
+                                    if active_widget == ActiveWidget::Stdout {
+                                        stdout_hscroll.scroll_right(1);
+                                    } else {
+                                        stderr_hscroll.scroll_right(1);
+                                    }
+                                }
+                                KeyCode::Up if view_mode == ViewMode::Merged => {
+                                    merged_scroll_offset = merged_scroll_offset.saturating_sub(1);
+                                    merged_autoscroll = false;
+                                }
+                                KeyCode::Down if view_mode == ViewMode::Merged => {
+                                    merged_scroll_offset = merged_scroll_offset.saturating_add(1);
+                                    merged_autoscroll = false;
+                                }
+                                KeyCode::PageUp if view_mode == ViewMode::Merged => {
+                                    merged_scroll_offset = merged_scroll_offset.saturating_sub(scroll_page);
+                                    merged_autoscroll = false;
+                                }
+                                KeyCode::PageDown if view_mode == ViewMode::Merged => {
+                                    merged_scroll_offset = merged_scroll_offset.saturating_add(scroll_page);
+                                    merged_autoscroll = false;
+                                }
+                                KeyCode::Home if view_mode == ViewMode::Merged => {
+                                    merged_scroll_offset = 0;
+                                    merged_autoscroll = false;
+                                }
+                                KeyCode::End if view_mode == ViewMode::Merged => {
+                                    merged_autoscroll = true;
+                                }
                                 KeyCode::Up => {
+                                    // scrolling up means moving further back into
+                                    // the emulator's scrollback (a larger offset)
                                     if active_widget == ActiveWidget::Stdout {
-                                        stdout_scroll_offset = stdout_scroll_offset.saturating_sub(1);
+                                        stdout_scroll_offset = stdout_scroll_offset.saturating_add(1);
                                         stdout_autoscroll = false;
                                     } else {
-                                        stderr_scroll_offset = stderr_scroll_offset.saturating_sub(1);
+                                        stderr_scroll_offset = stderr_scroll_offset.saturating_add(1);
                                         stderr_autoscroll = false;
                                     }
                                 }
                                 KeyCode::Down => {
                                     if active_widget == ActiveWidget::Stdout {
-                                        stdout_scroll_offset = stdout_scroll_offset.saturating_add(1);
+                                        stdout_scroll_offset = stdout_scroll_offset.saturating_sub(1);
                                         stdout_autoscroll = false;
                                     } else {
-                                        stderr_scroll_offset = stderr_scroll_offset.saturating_add(1);
+                                        stderr_scroll_offset = stderr_scroll_offset.saturating_sub(1);
                                         stderr_autoscroll = false;
                                     }
                                 }
                                 KeyCode::PageUp => {
-                                    if active_widget == ActiveWidget::Stdout {
-                                        stdout_scroll_offset = stdout_scroll_offset.saturating_sub(scroll_page);
+                                    // Shift turns PageUp/Down into a horizontal jump
+                                    if shift {
+                                        if active_widget == ActiveWidget::Stdout {
+                                            stdout_hscroll.scroll_left(scroll_page as u16);
+                                        } else {
+                                            stderr_hscroll.scroll_left(scroll_page as u16);
+                                        }
+                                    } else if active_widget == ActiveWidget::Stdout {
+                                        stdout_scroll_offset = stdout_scroll_offset.saturating_add(scroll_page);
                                         stdout_autoscroll = false;
                                     } else {
-                                        stderr_scroll_offset = stderr_scroll_offset.saturating_sub(scroll_page);
+                                        stderr_scroll_offset = stderr_scroll_offset.saturating_add(scroll_page);
                                         stderr_autoscroll = false;
                                     }
                                 }
                                 KeyCode::PageDown => {
-                                    if active_widget == ActiveWidget::Stdout {
-                                        stdout_scroll_offset = stdout_scroll_offset.saturating_add(scroll_page);
+                                    if shift {
+                                        if active_widget == ActiveWidget::Stdout {
+                                            stdout_hscroll.scroll_right(scroll_page as u16);
+                                        } else {
+                                            stderr_hscroll.scroll_right(scroll_page as u16);
+                                        }
+                                    } else if active_widget == ActiveWidget::Stdout {
+                                        stdout_scroll_offset = stdout_scroll_offset.saturating_sub(scroll_page);
                                         stdout_autoscroll = false;
                                     } else {
-                                        stderr_scroll_offset = stderr_scroll_offset.saturating_add(scroll_page);
+                                        stderr_scroll_offset = stderr_scroll_offset.saturating_sub(scroll_page);
                                         stderr_autoscroll = false;
                                     }
                                 }
                                 KeyCode::Home => {
+                                    // jump to the oldest retained line; the draw
+                                    // clamps this to the real scrollback length
                                     if active_widget == ActiveWidget::Stdout {
-                                        stdout_scroll_offset = 0;
+                                        stdout_scroll_offset = SCROLLBACK_LEN;
                                         stdout_autoscroll = false;
                                     } else {
-                                        stderr_scroll_offset = 0;
+                                        stderr_scroll_offset = SCROLLBACK_LEN;
                                         stderr_autoscroll = false;
                                     }
                                 }
@@ -175,15 +709,75 @@ async fn main() -> anyhow::Result<()> {
                                 }
                                 _ => {}
                             }
+                            }
+                            _ => {}
                         }
                     }
                     _ => {}
                 }
             }
+            _ = tick.tick() => {}
         }
 
         if terminal
             .draw(|frame| {
+                if view_mode == ViewMode::Merged {
+                    // a single pane showing both streams interleaved by arrival
+                    // time, with a timestamp + source gutter on each line
+                    let area = frame.area();
+                    let inner_h = area.height.saturating_sub(2) as usize;
+
+                    // extend the cache with only the newly-arrived events
+                    for event in &merged_log[merged_rendered..] {
+                        merged_lines.extend(event_to_lines(event));
+                    }
+                    merged_rendered = merged_log.len();
+                    let total = merged_lines.len();
+
+                    if merged_scroll_offset + inner_h >= total {
+                        merged_autoscroll = true;
+                    }
+                    if merged_autoscroll {
+                        merged_scroll_offset = total.saturating_sub(inner_h);
+                    }
+
+                    // hand the paragraph only the visible slice so rendering is
+                    // O(pane height) rather than O(total output)
+                    let start = merged_scroll_offset.min(total);
+                    let end = (start + inner_h).min(total);
+                    let visible = merged_lines[start..end].to_vec();
+
+                    let mut scrollbar_state = ScrollbarState::new(total.saturating_sub(inner_h))
+                        .position(merged_scroll_offset);
+                    let panel = Paragraph::new(visible)
+                        .block(
+                            Block::new()
+                                .title_top("merged")
+                                .title_top(
+                                    Line::from(if merged_autoscroll {
+                                        "autoscrolling"
+                                    } else {
+                                        ""
+                                    })
+                                    .right_aligned(),
+                                )
+                                .borders(Borders::ALL),
+                        );
+                    let scrollbar = Scrollbar::new(
+                        ratatui::widgets::ScrollbarOrientation::VerticalRight,
+                    );
+                    frame.render_widget(panel, area);
+                    frame.render_stateful_widget(
+                        scrollbar,
+                        area.inner(Margin {
+                            vertical: 1,
+                            horizontal: 0,
+                        }),
+                        &mut scrollbar_state,
+                    );
+                    return;
+                }
+
                 let layout = Layout::default()
                     .direction(ratatui::layout::Direction::Horizontal)
                     .constraints(
@@ -195,41 +789,53 @@ async fn main() -> anyhow::Result<()> {
                     )
                     .split(frame.area());
 
-                // don't let ratatui do the wrapping, we'll do it ourselves with `textwrap`
-                let stdout_width = layout[0].width as usize - 2;
-                let stderr_width = layout[1].width as usize - 2;
-                let height = layout[0].height as usize - 2;
-
-                let all_stdout = stdout_buf.concat();
-                let o: Vec<_> = textwrap::wrap(&all_stdout, stdout_width)
-                    .into_iter()
-                    .map(|line| Line::from(line))
-                    .collect();
-                let all_stderr = stderr_buf.concat();
-                let e: Vec<_> = textwrap::wrap(&all_stderr, stderr_width)
-                    .into_iter()
-                    .map(|line| Line::from(line))
-                    .collect();
-
-                if stdout_scroll_offset + height >= o.len() {
+                // the emulator grids match each pane's interior when wrapping is
+                // on; when off they're widened so logical lines stay un-wrapped
+                // and are panned across with the horizontal scroll offset
+                let out_rows = layout[0].height.saturating_sub(2);
+                let out_cols = layout[0].width.saturating_sub(2);
+                let err_rows = layout[1].height.saturating_sub(2);
+                let err_cols = layout[1].width.saturating_sub(2);
+                stdout_parser.set_size(out_rows, if stdout_wrap { out_cols } else { UNWRAPPED_COLS });
+                stderr_parser.set_size(err_rows, if stderr_wrap { err_cols } else { UNWRAPPED_COLS });
+                stdout_hscroll.set_max(UNWRAPPED_COLS.saturating_sub(out_cols));
+                stderr_hscroll.set_max(UNWRAPPED_COLS.saturating_sub(err_cols));
+                let stdout_h = if stdout_wrap { 0 } else { stdout_hscroll.pos };
+                let stderr_h = if stderr_wrap { 0 } else { stderr_hscroll.pos };
+
+                // probe each emulator for how much scrollback it actually holds
+                // (set_scrollback clamps, so the read-back tells us the maximum)
+                stdout_parser.set_scrollback(usize::MAX);
+                let stdout_sb_len = stdout_parser.screen().scrollback();
+                stderr_parser.set_scrollback(usize::MAX);
+                let stderr_sb_len = stderr_parser.screen().scrollback();
+
+                // clamp to what's available; reaching the bottom re-enables follow
+                stdout_scroll_offset = stdout_scroll_offset.min(stdout_sb_len);
+                stderr_scroll_offset = stderr_scroll_offset.min(stderr_sb_len);
+                if stdout_scroll_offset == 0 {
                     stdout_autoscroll = true;
                 }
-                if stdout_autoscroll {
-                    // set a scroll offset so that the last line is always visible
-                    stdout_scroll_offset = o.len().saturating_sub(height);
-                }
-
-                if stderr_scroll_offset + height >= e.len() {
+                if stderr_scroll_offset == 0 {
                     stderr_autoscroll = true;
                 }
+                // autoscroll == follow the live screen (scrollback offset 0)
+                if stdout_autoscroll {
+                    stdout_scroll_offset = 0;
+                }
                 if stderr_autoscroll {
-                    // set a scroll offset so that the last line is always visible
-                    stderr_scroll_offset = e.len().saturating_sub(height);
+                    stderr_scroll_offset = 0;
                 }
+                stdout_parser.set_scrollback(stdout_scroll_offset);
+                stderr_parser.set_scrollback(stderr_scroll_offset);
+
+                let o = screen_to_text(stdout_parser.screen());
+                let e = screen_to_text(stderr_parser.screen());
 
-                let mut stdout_scrollbar_state =
-                    ScrollbarState::new(o.len().saturating_sub(height))
-                        .position(stdout_scroll_offset);
+                // the scrollbar tracks position within the available scrollback;
+                // offset 0 (follow) sits at the bottom
+                let mut stdout_scrollbar_state = ScrollbarState::new(stdout_sb_len)
+                    .position(stdout_sb_len.saturating_sub(stdout_scroll_offset));
                 let stdout_panel = Paragraph::new(o)
                     .block(
                         Block::new()
@@ -250,13 +856,12 @@ async fn main() -> anyhow::Result<()> {
                                 Style::default()
                             }),
                     )
-                    .scroll((stdout_scroll_offset as u16, 0));
+                    .scroll((0, stdout_h));
                 let stdout_scrollbar =
                     Scrollbar::new(ratatui::widgets::ScrollbarOrientation::VerticalRight);
 
-                let mut stderr_scrollbar_state =
-                    ScrollbarState::new(e.len().saturating_sub(height))
-                        .position(stderr_scroll_offset);
+                let mut stderr_scrollbar_state = ScrollbarState::new(stderr_sb_len)
+                    .position(stderr_sb_len.saturating_sub(stderr_scroll_offset));
                 let stderr_panel = Paragraph::new(e)
                     .block(
                         Block::new()
@@ -277,7 +882,7 @@ async fn main() -> anyhow::Result<()> {
                                 Style::default()
                             }),
                     )
-                    .scroll((stderr_scroll_offset as u16, 0));
+                    .scroll((0, stderr_h));
                 let stderr_scrollbar =
                     Scrollbar::new(ratatui::widgets::ScrollbarOrientation::VerticalRight);
 
@@ -308,6 +913,18 @@ async fn main() -> anyhow::Result<()> {
 
     ratatui::restore();
 
+    // make sure any buffered capture actually hits disk
+    for file in [
+        save_stdout_file.as_mut(),
+        save_stderr_file.as_mut(),
+        save_transcript_file.as_mut(),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        file.flush().context("Failed to flush captured output")?;
+    }
+
     Ok(())
 }
 
@@ -319,3 +936,50 @@ fn test_wrap() {
     let w = textwrap::wrap(text, 20);
     dbg!(w);
 }
+
+#[test]
+fn transcript_round_trip() {
+    let events = vec![
+        OutputEvent {
+            when: std::time::Duration::from_millis(12),
+            source: Source::Stdout,
+            bytes: b"hello\nworld\n".to_vec(),
+        },
+        OutputEvent {
+            when: std::time::Duration::from_millis(3400),
+            source: Source::Stderr,
+            bytes: b"oops, no trailing newline".to_vec(),
+        },
+    ];
+
+    let mut buf = Vec::new();
+    for event in &events {
+        write_transcript_event(&mut buf, event).unwrap();
+    }
+    let parsed = parse_transcript(&buf).unwrap();
+
+    assert_eq!(parsed.len(), events.len());
+    for (got, want) in parsed.iter().zip(&events) {
+        assert_eq!(got.when, want.when);
+        assert_eq!(got.source, want.source);
+        assert_eq!(got.bytes, want.bytes);
+    }
+}
+
+#[test]
+fn transcript_truncated_body_errors() {
+    let mut buf = Vec::new();
+    write_transcript_event(
+        &mut buf,
+        &OutputEvent {
+            when: std::time::Duration::from_millis(5),
+            source: Source::Stdout,
+            bytes: b"hello".to_vec(),
+        },
+    )
+    .unwrap();
+
+    // chop bytes off the body so the recorded length overruns the data
+    buf.truncate(buf.len() - 3);
+    assert!(parse_transcript(&buf).is_err());
+}